@@ -28,6 +28,7 @@ impl Gender {
 #[derive(Debug, ErrorGen)]
 pub struct InvalidName;
 
+#[allow(dead_code)] // only ever printed via the derived `Debug`
 #[derive(Debug)]
 pub struct Name {
     given: String,
@@ -56,6 +57,7 @@ impl FromStr for Name {
     }
 }
 
+#[allow(dead_code)] // only ever printed via the derived `Debug`
 #[derive(Debug)]
 pub struct Person {
     name: Name,
@@ -5,11 +5,95 @@
 //! useful for building simple interactive command line apps (for example how *pacman* gets
 //! confirmantion during a system upgrade on arch linux). They are also useful for learning, when
 //! you want to be able to get data easily.
+//!
+//! With the `readline` feature enabled, prompts are read through `rustyline` instead of a plain
+//! `read_line`, which adds cursor movement and a history shared across prompts.
 use std::{
-    io::{self, Write},
+    io::{self, BufRead, Write},
     str::FromStr,
 };
 
+#[cfg(feature = "readline")]
+mod readline;
+
+mod prompt;
+mod style;
+
+pub use prompt::Prompt;
+pub use style::PromptStyle;
+
+/// Read a line from stdin, returning an `UnexpectedEof` error if stdin is closed.
+///
+/// `io::stdin().read_line` returns `Ok(0)` with an empty buffer on EOF, which looks exactly like a
+/// blank line. Without this check, the looping `read_*` functions would spin forever printing
+/// "Input must not be empty." once stdin runs out (piped input exhausted, or the user hits Ctrl-D).
+pub(crate) fn read_line() -> io::Result<String> {
+    #[cfg(feature = "readline")]
+    {
+        readline::prompt_line("")
+    }
+    #[cfg(not(feature = "readline"))]
+    {
+        let mut buf = String::new();
+        let n = io::stdin().read_line(&mut buf)?;
+        if n == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "reached end of input while reading from stdin",
+            ));
+        }
+        Ok(buf)
+    }
+}
+
+/// Display `prompt` and read back a line, via the `readline` feature's backend if enabled.
+pub(crate) fn prompt_line(prompt: &str) -> io::Result<String> {
+    #[cfg(feature = "readline")]
+    {
+        readline::prompt_line(prompt)
+    }
+    #[cfg(not(feature = "readline"))]
+    {
+        print!("{}", prompt);
+        io::stdout().flush()?;
+        read_line()
+    }
+}
+
+/// Read a line from an arbitrary reader, returning an `UnexpectedEof` error if it is closed.
+pub(crate) fn read_line_from(r: &mut impl BufRead) -> io::Result<String> {
+    let mut buf = String::new();
+    let n = r.read_line(&mut buf)?;
+    if n == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "reached end of input",
+        ));
+    }
+    Ok(buf)
+}
+
+/// Like [`read_string`], but writes the question to `w` and reads the answer from `r` instead of
+/// stdout/stdin. See [`Prompt::get_from`] for why this is useful.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::io::{self, Cursor};
+/// let mut output = Vec::new();
+/// let mut input = Cursor::new(b"Alice\n" as &[u8]);
+/// let name = read_human::read_string_to(&mut output, &mut input, "What is your name")?;
+/// assert_eq!(name.as_deref(), Some("Alice"));
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn read_string_to(
+    w: &mut impl Write,
+    r: &mut impl BufRead,
+    question: &str,
+) -> io::Result<Option<String>> {
+    Prompt::new(question).get_opt_from(w, r)
+}
+
 /// Get a line of text from the user.
 ///
 /// The question is displayed first. This method converts empty text into `None`. Any whitespace
@@ -27,15 +111,7 @@ use std::{
 /// # Ok::<(), io::Error>(())
 /// ```
 pub fn read_string(question: &str) -> io::Result<Option<String>> {
-    print!("{}: ", question);
-    io::stdout().flush()?;
-    let mut buf = String::new();
-    io::stdin().read_line(&mut buf)?;
-    Ok(if buf.trim() == "" {
-        None
-    } else {
-        Some(buf.trim().to_owned())
-    })
+    Prompt::new(question).get_opt()
 }
 
 /// Get a line of non-empty text from the user.
@@ -55,12 +131,7 @@ pub fn read_string(question: &str) -> io::Result<Option<String>> {
 /// # Ok::<(), io::Error>(())
 /// ```
 pub fn read_string_nonempty(question: &str) -> io::Result<String> {
-    loop {
-        match read_string(question)? {
-            Some(s) => return Ok(s),
-            None => println!("Input must not be empty."),
-        };
-    }
+    Prompt::new(question).get()
 }
 
 /// Get a line of from the user without displaying a question first.
@@ -81,8 +152,7 @@ pub fn read_string_nonempty(question: &str) -> io::Result<String> {
 /// ```
 pub fn read_string_noquestion() -> io::Result<Option<String>> {
     io::stdout().flush()?;
-    let mut buf = String::new();
-    io::stdin().read_line(&mut buf)?;
+    let buf = read_line()?;
     Ok(if buf.trim() == "" {
         None
     } else {
@@ -122,24 +192,22 @@ pub fn read_choice(
         "default index must be in the options slice"
     );
     loop {
-        print!("{} [", question);
+        let mut prompt = format!("{} [", PromptStyle::current_default().decorate(question));
         let mut options_iter = options.iter().enumerate();
         if let Some((_, opt)) = options_iter.next() {
-            print!(r#"1: "{}""#, opt.as_ref());
+            prompt += &format!(r#"1: "{}""#, opt.as_ref());
         }
         for (idx, option) in options_iter {
-            print!(r#", {}: "{}""#, idx + 1, option.as_ref());
+            prompt += &format!(r#", {}: "{}""#, idx + 1, option.as_ref());
         }
         if let Some(d) = default {
-            print!(" (default: {})", d + 1);
+            prompt += &format!(" (default: {})", d + 1);
         }
-        print!("]: ");
-        io::stdout().flush()?;
-        let mut buf = String::new();
-        io::stdin().read_line(&mut buf)?;
+        prompt += "]: ";
+        let buf = prompt_line(&prompt)?;
         let ans = buf.trim();
         if let Some(val) = default {
-            if ans == "" {
+            if ans.is_empty() {
                 return Ok(val);
             }
         }
@@ -159,6 +227,81 @@ pub fn read_choice(
     }
 }
 
+/// Ask the user a yes/no question, e.g. `Proceed with installation? [Y/n]`.
+///
+/// `default` controls what happens if the user just hits enter, and which option is capitalized
+/// in the displayed `[Y/n]`/`[y/N]` hint. If `default` is `None`, an empty line is re-asked like
+/// any other unrecognized answer.
+///
+/// Accepts `y`, `yes`, `n` and `no`, case-insensitively. Anything else is rejected and the
+/// question is asked again.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use std::io;
+/// let proceed = read_human::read_confirm("Proceed with installation?", Some(true))?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn read_confirm(question: &str, default: Option<bool>) -> io::Result<bool> {
+    let hint = confirm_hint(default);
+    let question = PromptStyle::current_default().decorate(question);
+    loop {
+        let buf = prompt_line(&format!("{} [{}]: ", question, hint))?;
+        let ans = buf.trim();
+        if ans.is_empty() {
+            if let Some(default) = default {
+                return Ok(default);
+            }
+            println!("Please answer yes or no.");
+            continue;
+        }
+        match ans.to_lowercase().as_str() {
+            "y" | "yes" => return Ok(true),
+            "n" | "no" => return Ok(false),
+            _ => println!("Please answer yes or no."),
+        }
+    }
+}
+
+/// Like [`read_confirm`], but writes the question to `w` and reads the answer from `r` instead of
+/// stdout/stdin. See [`Prompt::get_from`] for why this is useful.
+pub fn read_confirm_to(
+    w: &mut impl Write,
+    r: &mut impl BufRead,
+    question: &str,
+    default: Option<bool>,
+) -> io::Result<bool> {
+    let hint = confirm_hint(default);
+    let question = PromptStyle::current_default().decorate(question);
+    loop {
+        write!(w, "{} [{}]: ", question, hint)?;
+        w.flush()?;
+        let buf = read_line_from(r)?;
+        let ans = buf.trim();
+        if ans.is_empty() {
+            if let Some(default) = default {
+                return Ok(default);
+            }
+            writeln!(w, "Please answer yes or no.")?;
+            continue;
+        }
+        match ans.to_lowercase().as_str() {
+            "y" | "yes" => return Ok(true),
+            "n" | "no" => return Ok(false),
+            _ => writeln!(w, "Please answer yes or no.")?,
+        }
+    }
+}
+
+fn confirm_hint(default: Option<bool>) -> &'static str {
+    match default {
+        Some(true) => "Y/n",
+        Some(false) => "y/N",
+        None => "y/n",
+    }
+}
+
 /// Read in any type that implementd `FromStr` from stdio.
 ///
 /// If the text was empty, or couldn't be converted, then the user will be asked for more input.
@@ -171,13 +314,7 @@ pub fn read_choice(
 /// # Ok::<(), io::Error>(())
 /// ```
 pub fn read_custom_nonempty<T: FromStr>(question: &str) -> io::Result<T> {
-    loop {
-        let raw = read_string_nonempty(question)?;
-        match raw.parse::<T>() {
-            Ok(t) => return Ok(t),
-            Err(_) => println!("{} is not valid", raw),
-        }
-    }
+    Prompt::new(question).get()
 }
 
 /// Read in any type that implementd `FromStr` from stdio.
@@ -193,16 +330,7 @@ pub fn read_custom_nonempty<T: FromStr>(question: &str) -> io::Result<T> {
 /// # Ok::<(), io::Error>(())
 /// ```
 pub fn read_custom<T: FromStr>(question: &str) -> io::Result<Option<T>> {
-    loop {
-        let raw = match read_string(question)? {
-            Some(s) => s,
-            None => return Ok(None),
-        };
-        match raw.parse::<T>() {
-            Ok(t) => return Ok(Some(t)),
-            Err(_) => println!("{} is not valid", raw),
-        }
-    }
+    Prompt::new(question).get_opt()
 }
 
 /// Read in any type that implementd `FromStr` from stdio.
@@ -228,3 +356,233 @@ pub fn read_custom_noquestion<T: FromStr>() -> io::Result<Option<T>> {
         }
     }
 }
+
+/// Read any number of whitespace-separated values from a single line, e.g. `2 5 8`.
+///
+/// If any token fails to parse, the whole line is rejected (reporting which token was invalid)
+/// and the user is asked again.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use std::io;
+/// let numbers: Vec<u32> = read_human::read_many("Give me some numbers")?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn read_many<T: FromStr>(question: &str) -> io::Result<Vec<T>> {
+    let question = PromptStyle::current_default().decorate(question);
+    loop {
+        let buf = prompt_line(&format!("{}: ", question))?;
+        match parse_tokens(&buf) {
+            Ok(values) => return Ok(values),
+            Err(msg) => println!("{}", msg),
+        }
+    }
+}
+
+/// Like [`read_many`], but writes the question to `w` and reads the answer from `r` instead of
+/// stdout/stdin. See [`Prompt::get_from`] for why this is useful.
+pub fn read_many_to<T: FromStr>(
+    w: &mut impl Write,
+    r: &mut impl BufRead,
+    question: &str,
+) -> io::Result<Vec<T>> {
+    let question = PromptStyle::current_default().decorate(question);
+    loop {
+        write!(w, "{}: ", question)?;
+        w.flush()?;
+        let buf = read_line_from(r)?;
+        match parse_tokens(&buf) {
+            Ok(values) => return Ok(values),
+            Err(msg) => writeln!(w, "{}", msg)?,
+        }
+    }
+}
+
+/// Read exactly `n` whitespace-separated values from a single line, e.g. `2 5` for `n == 2`.
+///
+/// Re-asks the whole line if any token fails to parse, or if the number of tokens doesn't match
+/// `n`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use std::io;
+/// let pair: Vec<u32> = read_human::read_n("Give me two numbers", 2)?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn read_n<T: FromStr>(question: &str, n: usize) -> io::Result<Vec<T>> {
+    let question = PromptStyle::current_default().decorate(question);
+    loop {
+        let buf = prompt_line(&format!("{}: ", question))?;
+        match parse_tokens::<T>(&buf) {
+            Ok(values) if values.len() == n => return Ok(values),
+            Ok(values) => println!("expected {} value(s), but got {}", n, values.len()),
+            Err(msg) => println!("{}", msg),
+        }
+    }
+}
+
+/// Like [`read_n`], but writes the question to `w` and reads the answer from `r` instead of
+/// stdout/stdin. See [`Prompt::get_from`] for why this is useful.
+pub fn read_n_to<T: FromStr>(
+    w: &mut impl Write,
+    r: &mut impl BufRead,
+    question: &str,
+    n: usize,
+) -> io::Result<Vec<T>> {
+    let question = PromptStyle::current_default().decorate(question);
+    loop {
+        write!(w, "{}: ", question)?;
+        w.flush()?;
+        let buf = read_line_from(r)?;
+        match parse_tokens::<T>(&buf) {
+            Ok(values) if values.len() == n => return Ok(values),
+            Ok(values) => writeln!(w, "expected {} value(s), but got {}", n, values.len())?,
+            Err(msg) => writeln!(w, "{}", msg)?,
+        }
+    }
+}
+
+/// Split `line` on whitespace and parse every token, failing on the first one that doesn't parse.
+fn parse_tokens<T: FromStr>(line: &str) -> Result<Vec<T>, String> {
+    line.split_whitespace()
+        .map(|token| {
+            token
+                .parse::<T>()
+                .map_err(|_| format!("{} is not valid", token))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn read_line_from_reports_eof_instead_of_an_empty_line() {
+        let err = read_line_from(&mut Cursor::new(b"" as &[u8])).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn read_line_from_reads_a_line_normally() {
+        let line = read_line_from(&mut Cursor::new(b"hello\n" as &[u8])).unwrap();
+        assert_eq!(line, "hello\n");
+    }
+
+    #[test]
+    fn get_from_reports_eof_on_a_closed_reader() {
+        let mut output = Vec::new();
+        let err = Prompt::<String>::new("Name")
+            .get_from(&mut output, &mut Cursor::new(b"" as &[u8]))
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn read_confirm_to_accepts_the_default_on_an_empty_line() {
+        let mut output = Vec::new();
+        let ans = read_confirm_to(
+            &mut output,
+            &mut Cursor::new(b"\n" as &[u8]),
+            "Proceed?",
+            Some(true),
+        )
+        .unwrap();
+        assert!(ans);
+    }
+
+    #[test]
+    fn read_confirm_to_parses_yes_and_no_case_insensitively() {
+        let mut output = Vec::new();
+        assert!(read_confirm_to(
+            &mut output,
+            &mut Cursor::new(b"YES\n" as &[u8]),
+            "Proceed?",
+            None
+        )
+        .unwrap());
+        let mut output = Vec::new();
+        assert!(!read_confirm_to(
+            &mut output,
+            &mut Cursor::new(b"No\n" as &[u8]),
+            "Proceed?",
+            None
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn read_confirm_to_reprompts_on_an_unrecognized_answer() {
+        let mut output = Vec::new();
+        let ans = read_confirm_to(
+            &mut output,
+            &mut Cursor::new(b"maybe\ny\n" as &[u8]),
+            "Proceed?",
+            None,
+        )
+        .unwrap();
+        assert!(ans);
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .contains("Please answer yes or no."));
+    }
+
+    #[test]
+    fn read_many_to_parses_whitespace_separated_values() {
+        let mut output = Vec::new();
+        let values: Vec<u32> = read_many_to(
+            &mut output,
+            &mut Cursor::new(b"2 5 8\n" as &[u8]),
+            "Numbers",
+        )
+        .unwrap();
+        assert_eq!(values, vec![2, 5, 8]);
+    }
+
+    #[test]
+    fn read_many_to_reprompts_on_an_invalid_token() {
+        let mut output = Vec::new();
+        let values: Vec<u32> = read_many_to(
+            &mut output,
+            &mut Cursor::new(b"x\n2 5\n" as &[u8]),
+            "Numbers",
+        )
+        .unwrap();
+        assert_eq!(values, vec![2, 5]);
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .contains("x is not valid"));
+    }
+
+    #[test]
+    fn read_n_to_accepts_exactly_n_values() {
+        let mut output = Vec::new();
+        let values: Vec<u32> = read_n_to(
+            &mut output,
+            &mut Cursor::new(b"2 5\n" as &[u8]),
+            "Numbers",
+            2,
+        )
+        .unwrap();
+        assert_eq!(values, vec![2, 5]);
+    }
+
+    #[test]
+    fn read_n_to_reprompts_on_a_token_count_mismatch() {
+        let mut output = Vec::new();
+        let values: Vec<u32> = read_n_to(
+            &mut output,
+            &mut Cursor::new(b"2\n2 5\n" as &[u8]),
+            "Numbers",
+            2,
+        )
+        .unwrap();
+        assert_eq!(values, vec![2, 5]);
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .contains("expected 2 value(s), but got 1"));
+    }
+}
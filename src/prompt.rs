@@ -0,0 +1,209 @@
+//! A fluent builder for reading and validating a single value from the user.
+use std::{
+    io::{self, BufRead, Write},
+    str::FromStr,
+};
+
+use crate::PromptStyle;
+
+/// A validation rule, as passed to [`Prompt::validate`].
+type Validator<'a, T> = Box<dyn Fn(&T) -> Result<(), String> + 'a>;
+
+/// A builder for a single prompt, with an optional default and an optional validation rule.
+///
+/// This is a more flexible alternative to the free functions like [`read_custom`](crate::read_custom)
+/// and [`read_custom_nonempty`](crate::read_custom_nonempty): it lets you attach a typed default and
+/// arbitrary domain validation (e.g. "age must be under 150") in one place, then drive the same
+/// retry loop those functions use via [`get`](Prompt::get) or [`get_opt`](Prompt::get_opt).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use std::io;
+/// use read_human::Prompt;
+///
+/// let age: u8 = Prompt::new("What is your age")
+///     .default(18)
+///     .validate(|&age| {
+///         if age < 150 {
+///             Ok(())
+///         } else {
+///             Err("age must be under 150".to_owned())
+///         }
+///     })
+///     .get()?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub struct Prompt<'a, T> {
+    question: &'a str,
+    default: Option<T>,
+    validator: Option<Validator<'a, T>>,
+    style: PromptStyle,
+}
+
+impl<'a, T> Prompt<'a, T> {
+    /// Start building a prompt that will display `question` to the user.
+    pub fn new(question: &'a str) -> Self {
+        Prompt {
+            question,
+            default: None,
+            validator: None,
+            style: PromptStyle::current_default(),
+        }
+    }
+
+    /// Set the value to use if the user just hits enter.
+    pub fn default(mut self, value: T) -> Self {
+        self.default = Some(value);
+        self
+    }
+
+    /// Add a validation rule. If it returns `Err(msg)`, `msg` is shown to the user and they are
+    /// asked again.
+    pub fn validate(mut self, f: impl Fn(&T) -> Result<(), String> + 'a) -> Self {
+        self.validator = Some(Box::new(f));
+        self
+    }
+
+    /// Set how the question is decorated before being shown. Defaults to the thread's style at the
+    /// time [`Prompt::new`] was called (see [`PromptStyle::set_default`]), which is
+    /// [`PromptStyle::plain`] unless changed.
+    pub fn style(mut self, style: PromptStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn validate_value(&self, value: &T) -> Result<(), String> {
+        match &self.validator {
+            Some(validator) => validator(value),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a, T: FromStr> Prompt<'a, T> {
+    /// Run the prompt against stdin/stdout, returning the parsed and validated value.
+    ///
+    /// This will keep asking until it gets a value that parses and passes validation, or (if a
+    /// default was set) the user hits enter on an empty line.
+    pub fn get(self) -> io::Result<T> {
+        #[cfg(feature = "readline")]
+        {
+            self.get_via_prompt_line()
+        }
+        #[cfg(not(feature = "readline"))]
+        {
+            let stdin = io::stdin();
+            let mut reader = stdin.lock();
+            self.get_from(&mut io::stdout(), &mut reader)
+        }
+    }
+
+    /// Run the prompt against stdin/stdout, returning `None` if the user enters an empty line and
+    /// no default was set.
+    pub fn get_opt(self) -> io::Result<Option<T>> {
+        #[cfg(feature = "readline")]
+        {
+            self.get_opt_via_prompt_line()
+        }
+        #[cfg(not(feature = "readline"))]
+        {
+            let stdin = io::stdin();
+            let mut reader = stdin.lock();
+            self.get_opt_from(&mut io::stdout(), &mut reader)
+        }
+    }
+
+    /// Run the prompt against an arbitrary writer and reader rather than stdout/stdin.
+    ///
+    /// This is what makes prompts unit-testable without a TTY: pass a `Vec<u8>` for `w` and an
+    /// `io::Cursor` over some bytes for `r` to capture the question and drive the answer.
+    ///
+    /// Available regardless of the `readline` feature: only [`get`](Prompt::get) and
+    /// [`get_opt`](Prompt::get_opt) switch between the `rustyline` and plain `read_line` backends,
+    /// this method always reads and writes the arguments given.
+    pub fn get_from(mut self, w: &mut impl Write, r: &mut impl BufRead) -> io::Result<T> {
+        loop {
+            write!(w, "{}: ", self.style.decorate(self.question))?;
+            w.flush()?;
+            let raw = crate::read_line_from(r)?;
+            let raw = raw.trim();
+            if raw.is_empty() {
+                if let Some(default) = self.default.take() {
+                    return Ok(default);
+                }
+                writeln!(w, "Input must not be empty.")?;
+                continue;
+            }
+            match raw.parse::<T>() {
+                Ok(value) => match self.validate_value(&value) {
+                    Ok(()) => return Ok(value),
+                    Err(msg) => writeln!(w, "{}", msg)?,
+                },
+                Err(_) => writeln!(w, "{} is not valid", raw)?,
+            }
+        }
+    }
+
+    /// Run the prompt against an arbitrary writer and reader rather than stdout/stdin, returning
+    /// `None` on an empty line with no default set. See [`get_from`](Prompt::get_from) for why
+    /// this is available regardless of the `readline` feature.
+    pub fn get_opt_from(self, w: &mut impl Write, r: &mut impl BufRead) -> io::Result<Option<T>> {
+        loop {
+            write!(w, "{}: ", self.style.decorate(self.question))?;
+            w.flush()?;
+            let raw = crate::read_line_from(r)?;
+            let raw = raw.trim();
+            if raw.is_empty() {
+                return Ok(self.default);
+            }
+            match raw.parse::<T>() {
+                Ok(value) => match self.validate_value(&value) {
+                    Ok(()) => return Ok(Some(value)),
+                    Err(msg) => writeln!(w, "{}", msg)?,
+                },
+                Err(_) => writeln!(w, "{} is not valid", raw)?,
+            }
+        }
+    }
+
+    #[cfg(feature = "readline")]
+    fn get_via_prompt_line(mut self) -> io::Result<T> {
+        loop {
+            let buf = crate::prompt_line(&format!("{}: ", self.style.decorate(self.question)))?;
+            let raw = buf.trim();
+            if raw.is_empty() {
+                if let Some(default) = self.default.take() {
+                    return Ok(default);
+                }
+                println!("Input must not be empty.");
+                continue;
+            }
+            match raw.parse::<T>() {
+                Ok(value) => match self.validate_value(&value) {
+                    Ok(()) => return Ok(value),
+                    Err(msg) => println!("{}", msg),
+                },
+                Err(_) => println!("{} is not valid", raw),
+            }
+        }
+    }
+
+    #[cfg(feature = "readline")]
+    fn get_opt_via_prompt_line(self) -> io::Result<Option<T>> {
+        loop {
+            let buf = crate::prompt_line(&format!("{}: ", self.style.decorate(self.question)))?;
+            let raw = buf.trim();
+            if raw.is_empty() {
+                return Ok(self.default);
+            }
+            match raw.parse::<T>() {
+                Ok(value) => match self.validate_value(&value) {
+                    Ok(()) => return Ok(Some(value)),
+                    Err(msg) => println!("{}", msg),
+                },
+                Err(_) => println!("{} is not valid", raw),
+            }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+//! Line editing, history, and arrow-key recall via `rustyline`, enabled with the `readline`
+//! feature. This is an alternative backend for the `read_*` functions that read from stdin; the
+//! `*_to` functions and [`Prompt::get_from`](crate::Prompt::get_from) always use the reader they
+//! are given. It is not part of the public API.
+use std::{cell::RefCell, io};
+
+use rustyline::{error::ReadlineError, history::DefaultHistory, Editor};
+
+thread_local! {
+    static EDITOR: RefCell<io::Result<Editor<(), DefaultHistory>>> =
+        RefCell::new(Editor::new().map_err(to_io_error));
+}
+
+/// Display `prompt`, then read a line with editing and a history shared across prompts on this
+/// thread.
+pub(crate) fn prompt_line(prompt: &str) -> io::Result<String> {
+    EDITOR.with(|editor| {
+        let mut editor = editor.borrow_mut();
+        let editor = match editor.as_mut() {
+            Ok(editor) => editor,
+            Err(err) => return Err(io::Error::new(err.kind(), err.to_string())),
+        };
+        match editor.readline(prompt) {
+            Ok(line) => {
+                let _ = editor.add_history_entry(line.as_str());
+                Ok(line)
+            }
+            Err(ReadlineError::Eof) => Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "reached end of input while reading from stdin",
+            )),
+            Err(ReadlineError::Interrupted) => Err(io::Error::new(
+                io::ErrorKind::Interrupted,
+                "input was interrupted",
+            )),
+            Err(err) => Err(to_io_error(err)),
+        }
+    })
+}
+
+fn to_io_error(err: ReadlineError) -> io::Error {
+    io::Error::other(err)
+}
@@ -0,0 +1,86 @@
+//! Visual styling for prompts.
+use std::cell::Cell;
+
+thread_local! {
+    static DEFAULT_STYLE: Cell<PromptStyle> = Cell::new(PromptStyle::plain());
+}
+
+/// How a prompt's question is decorated before it is shown.
+///
+/// The default is [`PromptStyle::plain`], so existing output is unchanged unless a style is
+/// opted into, either per-prompt with [`Prompt::style`](crate::Prompt::style) or for every
+/// prompt on this thread (including the free functions like [`read_choice`](crate::read_choice)
+/// and [`read_confirm`](crate::read_confirm)) with [`PromptStyle::set_default`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PromptStyle {
+    marker: Option<&'static str>,
+}
+
+impl PromptStyle {
+    /// No marker, just the question text.
+    pub fn plain() -> Self {
+        PromptStyle { marker: None }
+    }
+
+    /// A bold `[?]` marker before the question, in the style of sn0int's prompts.
+    pub fn colored() -> Self {
+        PromptStyle {
+            marker: Some("\x1b[1m[?]\x1b[0m "),
+        }
+    }
+
+    /// Set the style used by every prompt on this thread that doesn't opt into its own with
+    /// [`Prompt::style`](crate::Prompt::style).
+    pub fn set_default(style: PromptStyle) {
+        DEFAULT_STYLE.with(|cell| cell.set(style));
+    }
+
+    /// The style currently used by prompts that don't set their own, see
+    /// [`PromptStyle::set_default`].
+    pub(crate) fn current_default() -> PromptStyle {
+        DEFAULT_STYLE.with(Cell::get)
+    }
+
+    pub(crate) fn decorate(&self, question: &str) -> String {
+        match self.marker {
+            Some(marker) => format!("{}{}", marker, question),
+            None => question.to_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn plain_leaves_the_question_untouched() {
+        assert_eq!(PromptStyle::plain().decorate("Proceed?"), "Proceed?");
+    }
+
+    #[test]
+    fn colored_prefixes_a_bold_marker() {
+        assert_eq!(
+            PromptStyle::colored().decorate("Proceed?"),
+            "\x1b[1m[?]\x1b[0m Proceed?"
+        );
+    }
+
+    #[test]
+    fn set_default_affects_the_free_functions() {
+        PromptStyle::set_default(PromptStyle::colored());
+        let mut output = Vec::new();
+        crate::read_confirm_to(
+            &mut output,
+            &mut Cursor::new(b"y\n" as &[u8]),
+            "Proceed?",
+            None,
+        )
+        .unwrap();
+        PromptStyle::set_default(PromptStyle::plain());
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .contains("\x1b[1m[?]\x1b[0m Proceed?"));
+    }
+}